@@ -0,0 +1,318 @@
+//! GPU-resident particle integration.
+//!
+//! macroquad exposes no compute-shader dispatch, so the pipeline here is a
+//! fragment shader that reads the previous frame's particle state texture
+//! and writes the next frame's into a render target, ping-ponged per
+//! `GpuParticleSim::step` call so the read and write targets never alias
+//! within a frame. Particle `i` lives at texel `(i % width, i / width)`,
+//! packed as `(pos.x, pos.y, vel.x, vel.y)`. The render target is an 8-bit
+//! RGBA texture, so raw world-space pos/vel (hundreds to thousands of units)
+//! would clip to the `0.0..=1.0` storable range; `encode_pos`/`decode_pos`
+//! and `encode_vel`/`decode_vel` remap them into that range on the way in
+//! and out, on both the CPU (initial upload, final readback) and the GPU
+//! (every shader invocation). Walls, attractor forces, drag, and the Euler
+//! step all happen in the shader; the only CPU/GPU round trip left is the
+//! single readback `draw_particles` needs, since macroquad has no instancing
+//! or point-sprite primitive that can sample a state texture directly.
+
+use crate::{Attractor, AttractorKind, Bounds, Particle};
+use egui_macroquad::macroquad::prelude::*;
+
+const MAX_ATTRACTORS: usize = 16;
+
+/// Velocities are encoded relative to `[-VEL_RANGE, VEL_RANGE]`; anything
+/// outside that is clamped, same as the pre-existing mouse/attractor force caps.
+const VEL_RANGE: f32 = 4000.0;
+
+const VERTEX_SHADER: &str = r#"#version 100
+attribute vec3 position;
+attribute vec2 texcoord;
+varying vec2 uv;
+uniform mat4 Model;
+uniform mat4 Projection;
+void main() {
+    uv = texcoord;
+    gl_Position = Projection * Model * vec4(position, 1.0);
+}
+"#;
+
+const FRAGMENT_SHADER: &str = r#"#version 100
+precision highp float;
+varying vec2 uv;
+
+uniform sampler2D state_tex;
+uniform float dt;
+uniform float drag;
+uniform vec2 bounds_min;
+uniform vec2 bounds_max;
+uniform float vel_range;
+uniform int attractor_count;
+uniform vec2 attractor_pos[16];
+uniform float attractor_kind[16];
+uniform float attractor_strength[16];
+uniform float attractor_attenuation[16];
+uniform float attractor_directionality[16];
+uniform float attractor_line_length[16];
+uniform float attractor_line_angle[16];
+
+vec2 decode_pos(vec2 encoded) {
+    return mix(bounds_min, bounds_max, encoded);
+}
+
+vec2 encode_pos(vec2 pos) {
+    return clamp((pos - bounds_min) / (bounds_max - bounds_min), 0.0, 1.0);
+}
+
+vec2 decode_vel(vec2 encoded) {
+    return (encoded * 2.0 - 1.0) * vel_range;
+}
+
+vec2 encode_vel(vec2 vel) {
+    return clamp(vel / vel_range, -1.0, 1.0) * 0.5 + 0.5;
+}
+
+void main() {
+    vec4 raw = texture2D(state_tex, uv);
+    vec2 pos = decode_pos(raw.xy);
+    vec2 vel = decode_vel(raw.zw);
+
+    if (pos.x < bounds_min.x || pos.x > bounds_max.x) {
+        pos.x = clamp(pos.x, bounds_min.x, bounds_max.x);
+        vel.x *= -1.0;
+    }
+    if (pos.y < bounds_min.y || pos.y > bounds_max.y) {
+        pos.y = clamp(pos.y, bounds_min.y, bounds_max.y);
+        vel.y *= -1.0;
+    }
+
+    vec2 acc = vec2(0.0, 0.0);
+    for (int i = 0; i < 16; i++) {
+        if (i >= attractor_count) break;
+
+        // kind 0 = Point, 1 = Swirl, 2 = Line (closest-point-on-segment below).
+        bool is_line = attractor_kind[i] > 1.5;
+        vec2 diff;
+        vec2 line_dir;
+        if (is_line) {
+            line_dir = vec2(cos(attractor_line_angle[i]), sin(attractor_line_angle[i]));
+            vec2 seg_start = attractor_pos[i] - line_dir * attractor_line_length[i] * 0.5;
+            vec2 seg_end = attractor_pos[i] + line_dir * attractor_line_length[i] * 0.5;
+            vec2 seg = seg_end - seg_start;
+            float len_squared = max(dot(seg, seg), 0.0001);
+            float t = clamp(dot(pos - seg_start, seg) / len_squared, 0.0, 1.0);
+            diff = pos - (seg_start + seg * t);
+        } else {
+            diff = pos - attractor_pos[i];
+        }
+
+        float dist = max(length(diff), 0.0001);
+        vec2 dir = diff / dist;
+        float mag = attractor_strength[i] / pow(dist, attractor_attenuation[i]);
+        vec2 radial = dir * mag;
+        vec2 local_dir = is_line ? line_dir : (attractor_kind[i] > 0.5 ? vec2(-dir.y, dir.x) : dir);
+        vec2 force = mix(radial, local_dir * mag, attractor_directionality[i]);
+        float force_len = length(force);
+        if (force_len > 2000.0) {
+            force = force / force_len * 2000.0;
+        }
+        acc += force;
+    }
+    acc -= drag * length(vel) * vel;
+
+    vel += acc * dt;
+    pos += vel * dt;
+
+    // bounce here, before encode_pos clamps pos into bounds and the
+    // out-of-bounds overshoot is lost
+    if (pos.x < bounds_min.x || pos.x > bounds_max.x) {
+        pos.x = clamp(pos.x, bounds_min.x, bounds_max.x);
+        vel.x *= -1.0;
+    }
+    if (pos.y < bounds_min.y || pos.y > bounds_max.y) {
+        pos.y = clamp(pos.y, bounds_min.y, bounds_max.y);
+        vel.y *= -1.0;
+    }
+
+    gl_FragColor = vec4(encode_pos(pos), encode_vel(vel));
+}
+"#;
+
+fn encode_pos(pos: Vec2, bounds: &Bounds) -> Vec2 {
+    ((pos - bounds.bottom_left) / (bounds.top_right - bounds.bottom_left)).clamp(Vec2::ZERO, Vec2::ONE)
+}
+
+fn decode_pos(encoded: Vec2, bounds: &Bounds) -> Vec2 {
+    bounds.bottom_left + encoded * (bounds.top_right - bounds.bottom_left)
+}
+
+fn encode_vel(vel: Vec2) -> Vec2 {
+    (vel / VEL_RANGE).clamp(Vec2::splat(-1.0), Vec2::splat(1.0)) * 0.5 + 0.5
+}
+
+fn decode_vel(encoded: Vec2) -> Vec2 {
+    (encoded * 2.0 - 1.0) * VEL_RANGE
+}
+
+/// Double-buffered GPU particle state: `read` is the frame just rendered,
+/// `read ^ 1` is written into by the next `step` and then becomes `read`.
+pub struct GpuParticleSim {
+    width: u32,
+    height: u32,
+    count: usize,
+    bounds_min: Vec2,
+    bounds_max: Vec2,
+    state: [RenderTarget; 2],
+    material: Material,
+    read: usize,
+}
+
+impl GpuParticleSim {
+    pub fn new(particles: &[Particle], bounds: &Bounds) -> Self {
+        let width = (particles.len() as f32).sqrt().ceil() as u32 + 1;
+        let height = width;
+
+        let mut image = Image::gen_image_color(width as u16, height as u16, BLANK);
+        for (i, p) in particles.iter().enumerate() {
+            let (x, y) = (i as u32 % width, i as u32 / width);
+            let encoded_pos = encode_pos(p.pos, bounds);
+            let encoded_vel = encode_vel(p.vel);
+            image.set_pixel(
+                x,
+                y,
+                Color::new(encoded_pos.x, encoded_pos.y, encoded_vel.x, encoded_vel.y),
+            );
+        }
+
+        let make_target = || {
+            let target = render_target(width, height);
+            target.texture.set_filter(FilterMode::Nearest);
+            target
+        };
+        let state = [make_target(), make_target()];
+        state[0].texture.update(&image);
+
+        let material = load_material(
+            ShaderSource::Glsl {
+                vertex: VERTEX_SHADER,
+                fragment: FRAGMENT_SHADER,
+            },
+            MaterialParams {
+                uniforms: vec![
+                    ("dt".to_string(), UniformType::Float1),
+                    ("drag".to_string(), UniformType::Float1),
+                    ("bounds_min".to_string(), UniformType::Float2),
+                    ("bounds_max".to_string(), UniformType::Float2),
+                    ("vel_range".to_string(), UniformType::Float1),
+                    ("attractor_count".to_string(), UniformType::Int1),
+                    ("attractor_pos".to_string(), UniformType::Float2),
+                    ("attractor_kind".to_string(), UniformType::Float1),
+                    ("attractor_strength".to_string(), UniformType::Float1),
+                    ("attractor_attenuation".to_string(), UniformType::Float1),
+                    ("attractor_directionality".to_string(), UniformType::Float1),
+                    ("attractor_line_length".to_string(), UniformType::Float1),
+                    ("attractor_line_angle".to_string(), UniformType::Float1),
+                ],
+                textures: vec!["state_tex".to_string()],
+                ..Default::default()
+            },
+        )
+        .expect("gpu particle shader failed to compile");
+
+        GpuParticleSim {
+            width,
+            height,
+            count: particles.len(),
+            bounds_min: bounds.bottom_left,
+            bounds_max: bounds.top_right,
+            state,
+            material,
+            read: 0,
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Integrates one frame entirely on the GPU, swapping the read/write targets.
+    pub fn step(&mut self, dt: f32, drag: f32, bounds: &Bounds, attractors: &[Attractor]) {
+        let write = 1 - self.read;
+
+        self.material.set_uniform("dt", dt);
+        self.material.set_uniform("drag", drag);
+        self.material.set_uniform("bounds_min", bounds.bottom_left);
+        self.material.set_uniform("bounds_max", bounds.top_right);
+        self.material.set_uniform("vel_range", VEL_RANGE);
+        self.material
+            .set_uniform("attractor_count", attractors.len().min(MAX_ATTRACTORS) as i32);
+        for (i, a) in attractors.iter().take(MAX_ATTRACTORS).enumerate() {
+            let kind = match a.kind {
+                AttractorKind::Point => 0.0,
+                AttractorKind::Swirl => 1.0,
+                AttractorKind::Line { .. } => 2.0,
+            };
+            let (line_length, line_angle) = match a.kind {
+                AttractorKind::Line { length, angle } => (length, angle),
+                AttractorKind::Point | AttractorKind::Swirl => (0.0, 0.0),
+            };
+            self.material.set_uniform(&format!("attractor_pos[{i}]"), a.pos);
+            self.material.set_uniform(&format!("attractor_kind[{i}]"), kind);
+            self.material
+                .set_uniform(&format!("attractor_strength[{i}]"), a.strength);
+            self.material
+                .set_uniform(&format!("attractor_attenuation[{i}]"), a.attenuation);
+            self.material
+                .set_uniform(&format!("attractor_directionality[{i}]"), a.directionality);
+            self.material
+                .set_uniform(&format!("attractor_line_length[{i}]"), line_length);
+            self.material
+                .set_uniform(&format!("attractor_line_angle[{i}]"), line_angle);
+        }
+        self.material
+            .set_texture("state_tex", self.state[self.read].texture.clone());
+
+        let mut camera = Camera2D::from_display_rect(Rect::new(
+            0.0,
+            0.0,
+            self.width as f32,
+            self.height as f32,
+        ));
+        camera.render_target = Some(self.state[write].clone());
+        set_camera(&camera);
+        gl_use_material(&self.material);
+        draw_rectangle(0.0, 0.0, self.width as f32, self.height as f32, WHITE);
+        gl_use_default_material();
+
+        self.read = write;
+        self.bounds_min = bounds.bottom_left;
+        self.bounds_max = bounds.top_right;
+    }
+
+    /// The only CPU/GPU sync point: pulls positions and velocities back so
+    /// the existing immediate-mode `draw_particles` can place circles.
+    ///
+    /// `prev` supplies `age`/`lifetime` for each particle, since the shader
+    /// doesn't run the lifecycle system — those fields just pass through
+    /// unchanged while GPU simulation is active.
+    pub fn read_back(&self, prev: &[Particle]) -> Vec<Particle> {
+        let image = self.state[self.read].texture.get_texture_data();
+        let bounds = Bounds {
+            bottom_left: self.bounds_min,
+            top_right: self.bounds_max,
+        };
+        prev.iter()
+            .enumerate()
+            .map(|(i, old)| {
+                let (x, y) = (i as u32 % self.width, i as u32 / self.width);
+                let c = image.get_pixel(x, y);
+                Particle {
+                    pos: decode_pos(vec2(c.r, c.g), &bounds),
+                    vel: decode_vel(vec2(c.b, c.a)),
+                    acc: Vec2::ZERO,
+                    age: old.age,
+                    lifetime: old.lifetime,
+                }
+            })
+            .collect()
+    }
+}
@@ -2,8 +2,18 @@ use egui_macroquad::{
     egui,
     macroquad::{self, prelude::*},
 };
+use gpu_sim::GpuParticleSim;
+use presets::Preset;
+// `Attractor` below derives `Serialize`/`Deserialize` and embeds `glam::Vec2`
+// (re-exported through macroquad), so the `glam` dependency pulled in by
+// macroquad must have its `serde` feature enabled in Cargo.toml, or preset
+// (de)serialization in presets.rs fails to compile.
+use serde::{Deserialize, Serialize};
 use std::f32::consts::PI;
 
+mod gpu_sim;
+mod presets;
+
 #[macroquad::main("Particle Interaction")]
 async fn main() {
     let bounds = Bounds {
@@ -17,8 +27,31 @@ async fn main() {
         interact_force: 5.5,
         drag: 2.0,
         trail_length: 66.0,
+        color_mode: ColorMode::Solid,
+        v_max: 500.0,
+        gpu_simulation: false,
+        fluid_enabled: false,
+        fluid_resolution: 32,
+        fluid_force_mult: 1.0,
+        fluid_dissipation: 0.1,
+        base_lifetime: 8.0,
+        lifetime_jitter: 2.0,
+        emitter_enabled: false,
     };
-    let mut particles = initialize_particles(&bounds, config.num_particles);
+    let mut particles = initialize_particles(
+        &bounds,
+        config.num_particles,
+        config.base_lifetime,
+        config.lifetime_jitter,
+    );
+    let mut attractors: Vec<Attractor> = Vec::new();
+    let mut selected_attractor: Option<usize> = None;
+    let mut gpu_sim: Option<GpuParticleSim> = None;
+    let mut fluid_grid = FluidGrid::new(config.fluid_resolution);
+    let mut prev_mouse_world = Vec2::ZERO;
+    let mut presets = presets::load_presets();
+    let mut selected_preset: Option<usize> = None;
+    let mut new_preset_name = String::new();
 
     let render_target = render_target(screen_width() as u32, screen_height() as u32);
     let texture_camera = {
@@ -36,21 +69,77 @@ async fn main() {
         if user_quit() {
             break;
         }
-        config_ui(&mut config, &bounds, &mut particles);
-
-        for _ in 0..config.simulation_speed {
-            update_particles(
-                &mut particles,
-                get_frame_time(),
-                convert_interact_force(config.interact_force),
-                convert_drag(config.drag),
-                &bounds,
-                |screen| {
-                    let mut world = texture_camera.screen_to_world(screen);
-                    world.y *= -1.0;
-                    world
-                },
-            );
+        let mut particles_reset = false;
+        let pointer_over_ui = config_ui(
+            &mut config,
+            &bounds,
+            &mut particles,
+            &mut attractors,
+            &mut selected_attractor,
+            &mut presets,
+            &mut selected_preset,
+            &mut new_preset_name,
+            &mut particles_reset,
+        );
+        if particles_reset {
+            gpu_sim = None;
+        }
+
+        let screen_to_world = |screen: Vec2| {
+            let mut world = texture_camera.screen_to_world(screen);
+            world.y *= -1.0;
+            world
+        };
+
+        let mouse_world = screen_to_world(mouse_position().into());
+
+        if !pointer_over_ui {
+            handle_attractor_click(&mut attractors, &mut selected_attractor, mouse_world);
+        }
+
+        if fluid_grid.resolution != config.fluid_resolution {
+            fluid_grid = FluidGrid::new(config.fluid_resolution);
+        }
+        if config.fluid_enabled && !pointer_over_ui && is_mouse_button_down(MouseButton::Right) {
+            let dt = get_frame_time();
+            if dt > 0.0 {
+                let mouse_vel = (mouse_world - prev_mouse_world) / dt;
+                fluid_grid.inject(&bounds, mouse_world, mouse_vel * config.fluid_force_mult);
+            }
+        }
+        fluid_grid.dissipate(config.fluid_dissipation);
+        prev_mouse_world = mouse_world;
+
+        if config.gpu_simulation {
+            if gpu_sim.as_ref().is_some_and(|s| s.count() != particles.len()) {
+                gpu_sim = None;
+            }
+            // the legacy Z/X/C mouse poke isn't ported to the shader; the
+            // attractor field (added alongside it) is the GPU path's only force source.
+            // Fluid advection and the lifetime/fade/respawn system don't run here either
+            // (config_ui disables their controls while GPU simulation is on).
+            let sim = gpu_sim.get_or_insert_with(|| GpuParticleSim::new(&particles, &bounds));
+            for _ in 0..config.simulation_speed {
+                sim.step(get_frame_time(), convert_drag(config.drag), &bounds, &attractors);
+            }
+            particles = sim.read_back(&particles);
+        } else {
+            gpu_sim = None;
+            for _ in 0..config.simulation_speed {
+                update_particles(
+                    &mut particles,
+                    get_frame_time(),
+                    convert_interact_force(config.interact_force),
+                    convert_drag(config.drag),
+                    &bounds,
+                    &attractors,
+                    config.fluid_enabled.then_some(&fluid_grid),
+                    config.base_lifetime,
+                    config.lifetime_jitter,
+                    config.emitter_enabled,
+                    screen_to_world,
+                );
+            }
         }
 
         // drawing to texture
@@ -62,7 +151,13 @@ async fn main() {
             screen_height(),
             Color::new(0.0, 0.0, 0.0, convert_trail_length(config.trail_length)),
         );
-        draw_particles(&particles, config.particle_radius);
+        draw_particles(
+            &particles,
+            config.particle_radius,
+            config.color_mode,
+            config.v_max,
+            &bounds,
+        );
 
         // drawing to the screen
         set_default_camera();
@@ -83,25 +178,45 @@ async fn main() {
     }
 }
 
-fn initialize_particles(bounds: &Bounds, num_particles: usize) -> Vec<Particle> {
+fn initialize_particles(
+    bounds: &Bounds,
+    num_particles: usize,
+    base_lifetime: f32,
+    lifetime_jitter: f32,
+) -> Vec<Particle> {
     (0..num_particles)
-        .map(|_| Particle {
-            pos: vec2(
-                rand::gen_range(bounds.bottom_left.x, bounds.top_right.x),
-                rand::gen_range(bounds.bottom_left.y, bounds.top_right.y),
-            ),
-            vel: vec2(0.0, 0.0),
-            acc: vec2(0.0, 0.0),
+        .map(|_| {
+            let lifetime = random_lifetime(base_lifetime, lifetime_jitter);
+            Particle {
+                pos: vec2(
+                    rand::gen_range(bounds.bottom_left.x, bounds.top_right.x),
+                    rand::gen_range(bounds.bottom_left.y, bounds.top_right.y),
+                ),
+                vel: vec2(0.0, 0.0),
+                acc: vec2(0.0, 0.0),
+                // stagger initial ages so particles don't all die in the same frame
+                age: rand::gen_range(0.0, lifetime),
+                lifetime,
+            }
         })
         .collect()
 }
 
+fn random_lifetime(base_lifetime: f32, lifetime_jitter: f32) -> f32 {
+    (base_lifetime + rand::gen_range(-lifetime_jitter, lifetime_jitter)).max(0.1)
+}
+
 fn update_particles(
     particles: &mut [Particle],
     dt: f32,
     interact_force: f32,
     drag: f32,
     bounds: &Bounds,
+    attractors: &[Attractor],
+    fluid_grid: Option<&FluidGrid>,
+    base_lifetime: f32,
+    lifetime_jitter: f32,
+    emitter_enabled: bool,
     screen_to_world: impl Fn(Vec2) -> Vec2,
 ) {
     let attract = is_key_down(KeyCode::Z);
@@ -109,7 +224,24 @@ fn update_particles(
     let swirl = is_key_down(KeyCode::C);
     let is_interacting = attract || repel || swirl;
 
+    let emitting = emitter_enabled && is_mouse_button_down(MouseButton::Middle);
+    let emit_point = emitting.then(|| screen_to_world(mouse_position().into()));
+
     for p in particles {
+        // lifetime: age, then fade and respawn once spent
+        p.age += dt;
+        if p.age >= p.lifetime {
+            p.pos = emit_point.unwrap_or_else(|| {
+                vec2(
+                    rand::gen_range(bounds.bottom_left.x, bounds.top_right.x),
+                    rand::gen_range(bounds.bottom_left.y, bounds.top_right.y),
+                )
+            });
+            p.vel = vec2(0.0, 0.0);
+            p.age = 0.0;
+            p.lifetime = random_lifetime(base_lifetime, lifetime_jitter);
+        }
+
         // bounce off walls
         if p.pos.x < bounds.bottom_left.x || p.pos.x > bounds.top_right.x {
             p.pos.x = p.pos.x.clamp(bounds.bottom_left.x, bounds.top_right.x);
@@ -140,25 +272,82 @@ fn update_particles(
             }
         }
 
+        // attractor field
+        for a in attractors {
+            p.acc += a.force_at(p.pos);
+        }
+
         // drag
         p.acc -= drag * p.vel.length() * p.vel;
 
         // motion
         p.vel += p.acc * dt;
+
+        // fluid advection: blend in the local grid velocity rather than adding to acc,
+        // so it reads as a current carrying the particle instead of another force
+        if let Some(grid) = fluid_grid {
+            p.vel = p.vel.lerp(grid.sample(bounds, p.pos), 0.5);
+        }
+
         p.pos += p.vel * dt;
     }
 }
 
-fn draw_particles(particles: &[Particle], radius: f32) {
+fn draw_particles(
+    particles: &[Particle],
+    radius: f32,
+    color_mode: ColorMode,
+    v_max: f32,
+    bounds: &Bounds,
+) {
+    let size = bounds.top_right - bounds.bottom_left;
     for p in particles {
-        draw_circle(p.pos.x, p.pos.y, radius, GREEN);
+        let mut color = match color_mode {
+            ColorMode::Solid => GREEN,
+            ColorMode::Speed => {
+                let t = (p.vel.length() / v_max).clamp(0.0, 1.0);
+                let hue = 0.66 * (1.0 - t);
+                let sat = 1.0 - 0.3 * ((p.pos.x - bounds.bottom_left.x) / size.x).clamp(0.0, 1.0);
+                hsv_to_rgb(hue, sat, 1.0)
+            }
+        };
+        color.a = fade_alpha(1.0 - p.age / p.lifetime);
+        draw_circle(p.pos.x, p.pos.y, radius, color);
     }
 }
 
+// Eases the lifetime fade with a smoothstep: particles linger near full
+// brightness early on, fade fastest around mid-life, and ease out to zero
+// at the end, rather than fading linearly throughout.
+fn fade_alpha(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+// Converts an HSV color (h, s, v in [0, 1]) to the RGB color macroquad expects.
+fn hsv_to_rgb(h: f32, s: f32, v: f32) -> Color {
+    let i = (h * 6.0).floor();
+    let f = h * 6.0 - i;
+    let p = v * (1.0 - s);
+    let q = v * (1.0 - f * s);
+    let t = v * (1.0 - (1.0 - f) * s);
+    let (r, g, b) = match i as i32 % 6 {
+        0 => (v, t, p),
+        1 => (q, v, p),
+        2 => (p, v, t),
+        3 => (p, q, v),
+        4 => (t, p, v),
+        _ => (v, p, q),
+    };
+    Color::new(r, g, b, 1.0)
+}
+
 struct Particle {
     pos: Vec2,
     vel: Vec2,
     acc: Vec2,
+    age: f32,
+    lifetime: f32,
 }
 
 struct Bounds {
@@ -166,6 +355,182 @@ struct Bounds {
     top_right: Vec2,
 }
 
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum ColorMode {
+    Solid,
+    Speed,
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum AttractorKind {
+    Point,
+    Swirl,
+    /// A line segment of `length` centered on the attractor's `pos`, at `angle` radians.
+    Line { length: f32, angle: f32 },
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct Attractor {
+    pos: Vec2,
+    kind: AttractorKind,
+    strength: f32,
+    attenuation: f32,
+    directionality: f32,
+}
+
+impl Attractor {
+    const SELECT_RADIUS: f32 = 20.0;
+    const DEFAULT_STRENGTH: f32 = 500000.0;
+    const MIN_STRENGTH: f32 = -2000000.0;
+    const MAX_STRENGTH: f32 = 2000000.0;
+    const MIN_ATTENUATION: f32 = 0.5;
+    const MAX_ATTENUATION: f32 = 3.0;
+    const MIN_LINE_LENGTH: f32 = 10.0;
+    const MAX_LINE_LENGTH: f32 = 500.0;
+    // Matches the mouse-interaction force clamp in update_particles so near-singularity
+    // distances (high strength, steep attenuation) can't blow up to NaN/Infinity.
+    const MAX_FORCE: f32 = 2000.0;
+
+    fn new(pos: Vec2) -> Self {
+        Attractor {
+            pos,
+            kind: AttractorKind::Point,
+            strength: Self::DEFAULT_STRENGTH,
+            attenuation: 1.0,
+            directionality: 0.0,
+        }
+    }
+
+    // Blends a purely radial pull/push with the attractor's kind-specific local axis.
+    fn force_at(&self, pos: Vec2) -> Vec2 {
+        let (diff, local_axis) = match self.kind {
+            AttractorKind::Point => (pos - self.pos, None),
+            AttractorKind::Swirl => (pos - self.pos, None),
+            AttractorKind::Line { length, angle } => {
+                let dir = Vec2::from_angle(angle);
+                let closest = closest_point_on_segment(
+                    pos,
+                    self.pos - dir * length / 2.0,
+                    self.pos + dir * length / 2.0,
+                );
+                (pos - closest, Some(dir))
+            }
+        };
+
+        let dist = diff.length();
+        if dist < f32::EPSILON {
+            return Vec2::ZERO;
+        }
+        let dir = diff / dist;
+        let mag = self.strength / dist.powf(self.attenuation);
+        let radial = dir * mag;
+        let local_dir = match (self.kind, local_axis) {
+            (AttractorKind::Swirl, _) => Mat2::from_angle(PI / 2.0).mul_vec2(dir),
+            (AttractorKind::Line { .. }, Some(axis)) => axis,
+            _ => dir,
+        };
+        radial
+            .lerp(local_dir * mag, self.directionality)
+            .clamp_length_max(Self::MAX_FORCE)
+    }
+}
+
+fn closest_point_on_segment(pos: Vec2, start: Vec2, end: Vec2) -> Vec2 {
+    let segment = end - start;
+    let len_squared = segment.length_squared();
+    if len_squared < f32::EPSILON {
+        return start;
+    }
+    let t = ((pos - start).dot(segment) / len_squared).clamp(0.0, 1.0);
+    start + segment * t
+}
+
+// Selects the attractor under the cursor, or drops a new point attractor there.
+fn handle_attractor_click(
+    attractors: &mut Vec<Attractor>,
+    selected_attractor: &mut Option<usize>,
+    world_pos: Vec2,
+) {
+    if is_mouse_button_pressed(MouseButton::Left) {
+        match attractors
+            .iter()
+            .position(|a| (a.pos - world_pos).length() < Attractor::SELECT_RADIUS)
+        {
+            Some(i) => *selected_attractor = Some(i),
+            None => {
+                attractors.push(Attractor::new(world_pos));
+                *selected_attractor = Some(attractors.len() - 1);
+            }
+        }
+    }
+    if is_mouse_button_pressed(MouseButton::Right) {
+        if let Some(i) = attractors
+            .iter()
+            .position(|a| (a.pos - world_pos).length() < Attractor::SELECT_RADIUS)
+        {
+            attractors.remove(i);
+            *selected_attractor = None;
+        }
+    }
+}
+
+/// A coarse grid of velocities covering `bounds`, advected particles sample
+/// by bilinear interpolation. Right-dragging the mouse injects force into
+/// the nearest cells (left click/drag is already claimed by attractor
+/// placement), and every cell decays a little each frame.
+struct FluidGrid {
+    resolution: usize,
+    cells: Vec<Vec2>,
+}
+
+impl FluidGrid {
+    fn new(resolution: usize) -> Self {
+        FluidGrid {
+            resolution,
+            cells: vec![Vec2::ZERO; resolution * resolution],
+        }
+    }
+
+    fn cell_coords(&self, bounds: &Bounds, pos: Vec2) -> (f32, f32) {
+        let size = bounds.top_right - bounds.bottom_left;
+        let t = (pos - bounds.bottom_left) / size;
+        (
+            (t.x * (self.resolution - 1) as f32).clamp(0.0, (self.resolution - 1) as f32),
+            (t.y * (self.resolution - 1) as f32).clamp(0.0, (self.resolution - 1) as f32),
+        )
+    }
+
+    fn cell(&mut self, x: usize, y: usize) -> &mut Vec2 {
+        &mut self.cells[y * self.resolution + x]
+    }
+
+    fn inject(&mut self, bounds: &Bounds, pos: Vec2, force: Vec2) {
+        let (cx, cy) = self.cell_coords(bounds, pos);
+        *self.cell(cx.round() as usize, cy.round() as usize) += force;
+    }
+
+    fn dissipate(&mut self, dissipation: f32) {
+        for cell in &mut self.cells {
+            *cell *= 1.0 - dissipation;
+        }
+    }
+
+    fn sample(&self, bounds: &Bounds, pos: Vec2) -> Vec2 {
+        let (cx, cy) = self.cell_coords(bounds, pos);
+        let (x0, y0) = (cx.floor() as usize, cy.floor() as usize);
+        let (x1, y1) = (
+            (x0 + 1).min(self.resolution - 1),
+            (y0 + 1).min(self.resolution - 1),
+        );
+        let (fx, fy) = (cx - x0 as f32, cy - y0 as f32);
+
+        let top = self.cells[y0 * self.resolution + x0].lerp(self.cells[y0 * self.resolution + x1], fx);
+        let bottom = self.cells[y1 * self.resolution + x0].lerp(self.cells[y1 * self.resolution + x1], fx);
+        top.lerp(bottom, fy)
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
 struct Config {
     simulation_speed: u32,
     num_particles: usize,
@@ -173,12 +538,22 @@ struct Config {
     interact_force: f32,
     drag: f32,
     trail_length: f32,
+    color_mode: ColorMode,
+    v_max: f32,
+    gpu_simulation: bool,
+    fluid_enabled: bool,
+    fluid_resolution: usize,
+    fluid_force_mult: f32,
+    fluid_dissipation: f32,
+    base_lifetime: f32,
+    lifetime_jitter: f32,
+    emitter_enabled: bool,
 }
 
 impl Config {
     const MAX_SIMULATION_SPEED: u32 = 3;
     const MIN_NUM_PARTICLES: usize = 1;
-    const MAX_NUM_PARTICLES: usize = 20000;
+    const MAX_NUM_PARTICLES: usize = 200000;
     const MIN_PARTICLE_RADIUS: f32 = 1.0;
     const MAX_PARTICLE_RADIUS: f32 = 5.0;
     const MIN_INTERACT_FORCE: f32 = 1.0;
@@ -186,6 +561,18 @@ impl Config {
     const MIN_DRAG: f32 = 0.0;
     const MAX_DRAG: f32 = 10.0;
     const MAX_TRAIL_LENGTH: f32 = 100.0;
+    const MIN_V_MAX: f32 = 50.0;
+    const MAX_V_MAX: f32 = 2000.0;
+    const MIN_FLUID_RESOLUTION: usize = 8;
+    const MAX_FLUID_RESOLUTION: usize = 128;
+    const MIN_FLUID_FORCE_MULT: f32 = 0.0;
+    const MAX_FLUID_FORCE_MULT: f32 = 5.0;
+    const MIN_FLUID_DISSIPATION: f32 = 0.0;
+    const MAX_FLUID_DISSIPATION: f32 = 1.0;
+    const MIN_BASE_LIFETIME: f32 = 0.5;
+    const MAX_BASE_LIFETIME: f32 = 30.0;
+    const MIN_LIFETIME_JITTER: f32 = 0.0;
+    const MAX_LIFETIME_JITTER: f32 = 10.0;
 }
 
 fn convert_interact_force(interact_force: f32) -> f32 {
@@ -212,7 +599,18 @@ fn user_quit() -> bool {
     is_key_released(KeyCode::Q)
 }
 
-fn config_ui(config: &mut Config, bounds: &Bounds, particles: &mut Vec<Particle>) {
+fn config_ui(
+    config: &mut Config,
+    bounds: &Bounds,
+    particles: &mut Vec<Particle>,
+    attractors: &mut Vec<Attractor>,
+    selected_attractor: &mut Option<usize>,
+    presets: &mut Vec<Preset>,
+    selected_preset: &mut Option<usize>,
+    new_preset_name: &mut String,
+    particles_reset: &mut bool,
+) -> bool {
+    let mut pointer_over_ui = false;
     egui_macroquad::ui(|ctx| {
         egui::Area::new("parameters")
             .fixed_pos((0.0, 0.0))
@@ -260,9 +658,226 @@ fn config_ui(config: &mut Config, bounds: &Bounds, particles: &mut Vec<Particle>
                         .text("Trail length")
                         .text_color(egui::Color32::WHITE),
                 );
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new("Coloring").color(egui::Color32::WHITE));
+                    ui.selectable_value(&mut config.color_mode, ColorMode::Solid, "Solid");
+                    ui.selectable_value(&mut config.color_mode, ColorMode::Speed, "Speed");
+                });
+                if config.color_mode == ColorMode::Speed {
+                    ui.add(
+                        egui::Slider::new(&mut config.v_max, Config::MIN_V_MAX..=Config::MAX_V_MAX)
+                            .text("Max speed")
+                            .text_color(egui::Color32::WHITE),
+                    );
+                }
+                ui.checkbox(
+                    &mut config.gpu_simulation,
+                    egui::RichText::new("GPU simulation (experimental)").color(egui::Color32::WHITE),
+                );
+
+                ui.separator();
+                if config.gpu_simulation {
+                    ui.label(
+                        egui::RichText::new(
+                            "Lifetime/fade/respawn and fluid advection are ignored while GPU \
+                             simulation is on — the shader only applies drag and attractor forces.",
+                        )
+                        .color(egui::Color32::LIGHT_RED),
+                    );
+                }
+                ui.add_enabled_ui(!config.gpu_simulation, |ui| {
+                    ui.add(
+                        egui::Slider::new(
+                            &mut config.base_lifetime,
+                            Config::MIN_BASE_LIFETIME..=Config::MAX_BASE_LIFETIME,
+                        )
+                        .text("Lifetime")
+                        .text_color(egui::Color32::WHITE)
+                        .suffix("s"),
+                    );
+                    ui.add(
+                        egui::Slider::new(
+                            &mut config.lifetime_jitter,
+                            Config::MIN_LIFETIME_JITTER..=Config::MAX_LIFETIME_JITTER,
+                        )
+                        .text("Lifetime jitter")
+                        .text_color(egui::Color32::WHITE)
+                        .suffix("s"),
+                    );
+                    ui.checkbox(
+                        &mut config.emitter_enabled,
+                        egui::RichText::new("Emitter (middle-drag spawns at cursor)")
+                            .color(egui::Color32::WHITE),
+                    );
+
+                    ui.separator();
+                    ui.checkbox(
+                        &mut config.fluid_enabled,
+                        egui::RichText::new("Fluid advection (right-drag to stir)")
+                            .color(egui::Color32::WHITE),
+                    );
+                    if config.fluid_enabled {
+                        ui.add(
+                            egui::Slider::new(
+                                &mut config.fluid_resolution,
+                                Config::MIN_FLUID_RESOLUTION..=Config::MAX_FLUID_RESOLUTION,
+                            )
+                            .text("Fluid grid resolution")
+                            .text_color(egui::Color32::WHITE),
+                        );
+                        ui.add(
+                            egui::Slider::new(
+                                &mut config.fluid_force_mult,
+                                Config::MIN_FLUID_FORCE_MULT..=Config::MAX_FLUID_FORCE_MULT,
+                            )
+                            .text("Fluid force multiplier")
+                            .text_color(egui::Color32::WHITE),
+                        );
+                        ui.add(
+                            egui::Slider::new(
+                                &mut config.fluid_dissipation,
+                                Config::MIN_FLUID_DISSIPATION..=Config::MAX_FLUID_DISSIPATION,
+                            )
+                            .text("Fluid dissipation")
+                            .text_color(egui::Color32::WHITE),
+                        );
+                    }
+                });
+
                 if ui.add(egui::Button::new("Reset")).clicked() {
-                    *particles = initialize_particles(bounds, config.num_particles);
+                    *particles = initialize_particles(
+                        bounds,
+                        config.num_particles,
+                        config.base_lifetime,
+                        config.lifetime_jitter,
+                    );
+                    *particles_reset = true;
+                }
+
+                ui.separator();
+                ui.label(egui::RichText::new("Presets").color(egui::Color32::WHITE));
+                egui::ComboBox::from_id_source("preset_select")
+                    .selected_text(
+                        selected_preset
+                            .and_then(|i| presets.get(i))
+                            .map(|p| p.name.as_str())
+                            .unwrap_or("(none)"),
+                    )
+                    .show_ui(ui, |ui| {
+                        for (i, preset) in presets.iter().enumerate() {
+                            ui.selectable_value(selected_preset, Some(i), &preset.name);
+                        }
+                    });
+                ui.horizontal(|ui| {
+                    if ui.add(egui::Button::new("Load")).clicked() {
+                        if let Some(preset) = selected_preset.and_then(|i| presets.get(i)) {
+                            *config = preset.config.clone();
+                            *attractors = preset.attractors.clone();
+                            *selected_attractor = None;
+                            *particles = initialize_particles(
+                                bounds,
+                                config.num_particles,
+                                config.base_lifetime,
+                                config.lifetime_jitter,
+                            );
+                            *particles_reset = true;
+                        }
+                    }
+                    if ui.add(egui::Button::new("Delete")).clicked() {
+                        if let Some(i) = *selected_preset {
+                            if !presets::is_built_in(&presets[i].name) {
+                                presets.remove(i);
+                                *selected_preset = None;
+                                presets::save_user_presets(presets);
+                            }
+                        }
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.text_edit_singleline(new_preset_name);
+                    if ui.add(egui::Button::new("Save as")).clicked()
+                        && !new_preset_name.is_empty()
+                        && !presets::is_built_in(new_preset_name)
+                    {
+                        let preset = Preset {
+                            name: new_preset_name.clone(),
+                            config: config.clone(),
+                            attractors: attractors.clone(),
+                        };
+                        match presets.iter().position(|p| p.name == preset.name) {
+                            Some(i) => presets[i] = preset,
+                            None => presets.push(preset),
+                        }
+                        presets::save_user_presets(presets);
+                        new_preset_name.clear();
+                    }
+                });
+
+                ui.separator();
+                ui.label(
+                    egui::RichText::new(format!(
+                        "Attractors: {} (click viewport to add, right-click to remove)",
+                        attractors.len()
+                    ))
+                    .color(egui::Color32::WHITE),
+                );
+                if let Some(a) = selected_attractor.and_then(|i| attractors.get_mut(i)) {
+                    ui.horizontal(|ui| {
+                        ui.label(egui::RichText::new("Kind").color(egui::Color32::WHITE));
+                        ui.selectable_value(&mut a.kind, AttractorKind::Point, "Point");
+                        ui.selectable_value(&mut a.kind, AttractorKind::Swirl, "Swirl");
+                        ui.selectable_value(
+                            &mut a.kind,
+                            AttractorKind::Line {
+                                length: 100.0,
+                                angle: 0.0,
+                            },
+                            "Line",
+                        );
+                    });
+                    ui.add(
+                        egui::Slider::new(
+                            &mut a.strength,
+                            Attractor::MIN_STRENGTH..=Attractor::MAX_STRENGTH,
+                        )
+                        .text("Strength")
+                        .text_color(egui::Color32::WHITE),
+                    );
+                    ui.add(
+                        egui::Slider::new(
+                            &mut a.attenuation,
+                            Attractor::MIN_ATTENUATION..=Attractor::MAX_ATTENUATION,
+                        )
+                        .text("Attenuation")
+                        .text_color(egui::Color32::WHITE),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut a.directionality, 0.0..=1.0)
+                            .text("Directionality")
+                            .text_color(egui::Color32::WHITE),
+                    );
+                    if let AttractorKind::Line { length, angle } = &mut a.kind {
+                        ui.add(
+                            egui::Slider::new(
+                                length,
+                                Attractor::MIN_LINE_LENGTH..=Attractor::MAX_LINE_LENGTH,
+                            )
+                            .text("Line length")
+                            .text_color(egui::Color32::WHITE),
+                        );
+                        ui.add(
+                            egui::Slider::new(angle, 0.0..=(2.0 * PI))
+                                .text("Line angle")
+                                .text_color(egui::Color32::WHITE),
+                        );
+                    }
+                    if ui.add(egui::Button::new("Remove attractor")).clicked() {
+                        let i = selected_attractor.take().unwrap();
+                        attractors.remove(i);
+                    }
                 }
             });
+        pointer_over_ui = ctx.wants_pointer_input();
     });
+    pointer_over_ui
 }
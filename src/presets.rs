@@ -0,0 +1,134 @@
+//! Named simulation presets, persisted to a small RON file (`presets.ron`) in the
+//! current working directory.
+//!
+//! A preset bundles the slider `Config` with the current attractor layout, so
+//! switching presets reproduces a whole look (forces included), not just the
+//! sliders. Built-in presets are always available even if the save file is
+//! missing or unreadable; user-saved presets are merged in on top of them.
+
+use crate::{Attractor, AttractorKind, ColorMode, Config};
+use egui_macroquad::macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+const PRESETS_FILE: &str = "presets.ron";
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Preset {
+    pub name: String,
+    pub config: Config,
+    pub attractors: Vec<Attractor>,
+}
+
+pub fn load_presets() -> Vec<Preset> {
+    let mut presets = built_in_presets();
+    if let Ok(contents) = fs::read_to_string(PRESETS_FILE) {
+        if let Ok(saved) = ron::from_str::<Vec<Preset>>(&contents) {
+            for preset in saved {
+                match presets.iter_mut().find(|p| p.name == preset.name) {
+                    Some(existing) => *existing = preset,
+                    None => presets.push(preset),
+                }
+            }
+        }
+    }
+    presets
+}
+
+pub fn save_user_presets(presets: &[Preset]) {
+    let built_in = built_in_presets();
+    let user_presets: Vec<&Preset> = presets
+        .iter()
+        .filter(|p| !built_in.iter().any(|b| b.name == p.name))
+        .collect();
+    if let Ok(contents) = ron::ser::to_string_pretty(&user_presets, ron::ser::PrettyConfig::default()) {
+        let _ = fs::write(PRESETS_FILE, contents);
+    }
+}
+
+pub fn is_built_in(name: &str) -> bool {
+    built_in_presets().iter().any(|p| p.name == name)
+}
+
+fn built_in_presets() -> Vec<Preset> {
+    vec![
+        Preset {
+            name: "Galaxy".to_string(),
+            config: Config {
+                simulation_speed: 1,
+                num_particles: 15000,
+                particle_radius: 1.2,
+                interact_force: 5.5,
+                drag: 0.5,
+                trail_length: 80.0,
+                color_mode: ColorMode::Speed,
+                v_max: 800.0,
+                gpu_simulation: false,
+                fluid_enabled: false,
+                fluid_resolution: 32,
+                fluid_force_mult: 1.0,
+                fluid_dissipation: 0.1,
+                base_lifetime: 12.0,
+                lifetime_jitter: 3.0,
+                emitter_enabled: false,
+            },
+            attractors: vec![Attractor {
+                pos: vec2(0.0, 0.0),
+                kind: AttractorKind::Swirl,
+                strength: 900000.0,
+                attenuation: 1.0,
+                directionality: 0.85,
+            }],
+        },
+        Preset {
+            name: "Smoke".to_string(),
+            config: Config {
+                simulation_speed: 1,
+                num_particles: 10000,
+                particle_radius: 1.5,
+                interact_force: 3.0,
+                drag: 1.0,
+                trail_length: 90.0,
+                color_mode: ColorMode::Solid,
+                v_max: 500.0,
+                gpu_simulation: false,
+                fluid_enabled: true,
+                fluid_resolution: 48,
+                fluid_force_mult: 2.0,
+                fluid_dissipation: 0.05,
+                base_lifetime: 6.0,
+                lifetime_jitter: 2.0,
+                emitter_enabled: false,
+            },
+            attractors: vec![],
+        },
+        Preset {
+            name: "Fireworks".to_string(),
+            config: Config {
+                simulation_speed: 1,
+                num_particles: 8000,
+                particle_radius: 1.8,
+                interact_force: 8.0,
+                drag: 3.0,
+                trail_length: 40.0,
+                color_mode: ColorMode::Speed,
+                v_max: 1500.0,
+                gpu_simulation: false,
+                fluid_enabled: false,
+                fluid_resolution: 32,
+                fluid_force_mult: 1.0,
+                fluid_dissipation: 0.1,
+                base_lifetime: 2.0,
+                lifetime_jitter: 1.0,
+                emitter_enabled: true,
+            },
+            attractors: vec![Attractor {
+                pos: vec2(0.0, 0.0),
+                kind: AttractorKind::Point,
+                strength: -1200000.0,
+                attenuation: 0.7,
+                directionality: 0.0,
+            }],
+        },
+    ]
+}